@@ -304,6 +304,10 @@ pub struct CompileArgs {
     /// pages. Use `{p}` for page numbers, `{0p}` for zero padded page numbers
     /// and `{t}` for page count. For example, `page-{0p}-of-{t}.png` creates
     /// `page-01-of-10.png`, `page-02-of-10.png`, and so on.
+    ///
+    /// For PDF output, such a template splits the document into one PDF per
+    /// page. Links between pages of the document do not work in this case, as
+    /// each page ends up in a separate file.
     #[clap(
          required_if_eq("input", "-"),
          value_parser = output_value_parser(),
@@ -344,6 +348,11 @@ pub struct CompileArgs {
     #[arg(long = "no-pdf-tags")]
     pub no_pdf_tags: bool,
 
+    /// Fail instead of emitting a warning when the license of a used font does
+    /// not permit embedding it into the PDF.
+    #[arg(long = "deny-restricted-fonts")]
+    pub deny_restricted_fonts: bool,
+
     /// The PPI (pixels per inch) to use for PNG export.
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f32,
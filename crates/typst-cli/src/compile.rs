@@ -1,8 +1,9 @@
 use std::ffi::OsStr;
-use std::path::Path;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Datelike, Timelike, Utc};
-use ecow::eco_format;
+use ecow::{EcoVec, eco_format};
 use parking_lot::RwLock;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use typst::diag::{
@@ -68,6 +69,11 @@ pub struct CompileConfig {
     pub pdf_standards: PdfStandards,
     /// Whether to write PDF (accessibility) tags.
     pub tagged: bool,
+    /// The page number template in the output path, if one PDF should be
+    /// written per page.
+    pub pdf_template: Option<String>,
+    /// Whether to fail on fonts whose license does not permit embedding.
+    pub deny_restricted_fonts: bool,
     /// A destination to write a list of dependencies to.
     pub deps: Option<Output>,
     /// The format to use for dependencies.
@@ -138,7 +144,18 @@ impl CompileConfig {
             PageRanges::new(export_ranges.iter().map(|r| r.0.clone()).collect())
         });
 
-        let tagged = !args.no_pdf_tags && pages.is_none();
+        // A page number template in a PDF output path splits the document
+        // into one PDF per page.
+        let pdf_template = match &output {
+            Output::Path(path) if output_format == OutputFormat::Pdf => path
+                .to_str()
+                .filter(|path| output_template::has_indexable_template(path))
+                .map(String::from),
+            _ => None,
+        };
+        let split_pdf = pdf_template.is_some();
+
+        let tagged = !args.no_pdf_tags && pages.is_none() && !split_pdf;
         if output_format == OutputFormat::Pdf && pages.is_some() && !args.no_pdf_tags {
             warnings.push(
                 HintedString::from("using --pages implies --no-pdf-tags").with_hints([
@@ -146,6 +163,14 @@ impl CompileConfig {
                     "add --no-pdf-tags to silence this warning".into(),
                 ]),
             );
+        } else if split_pdf && !args.no_pdf_tags {
+            warnings.push(
+                HintedString::from("exporting one PDF per page implies --no-pdf-tags")
+                    .with_hints([
+                        "the resulting PDFs will be inaccessible".into(),
+                        "add --no-pdf-tags to silence this warning".into(),
+                    ]),
+            );
         }
 
         if !tagged {
@@ -160,6 +185,11 @@ impl CompileConfig {
                 if args.pdf_standard.contains(standard) {
                     if args.no_pdf_tags {
                         bail!("cannot disable PDF tags when exporting a {name} document");
+                    } else if split_pdf {
+                        bail!(
+                            "cannot export one PDF per page as a {name} document";
+                            hint: "exporting one PDF per page implies --no-pdf-tags";
+                        );
                     } else {
                         bail!(
                             "cannot disable PDF tags when exporting a {name} document";
@@ -223,6 +253,8 @@ impl CompileConfig {
             pages,
             pdf_standards,
             tagged,
+            pdf_template,
+            deny_restricted_fonts: args.deny_restricted_fonts,
             creation_timestamp: args.world.creation_timestamp,
             ppi: args.ppi,
             diagnostic_format: args.process.diagnostic_format,
@@ -313,8 +345,9 @@ fn compile_and_export(
             }
         }
         _ => {
-            let Warned { output, warnings } = typst::compile::<PagedDocument>(world);
-            let result = output.and_then(|document| export_paged(&document, config));
+            let Warned { output, mut warnings } = typst::compile::<PagedDocument>(world);
+            let result = output
+                .and_then(|document| export_paged(&document, config, &mut warnings));
             Warned { output: result, warnings }
         }
     }
@@ -339,11 +372,10 @@ fn export_html(document: &HtmlDocument, config: &CompileConfig) -> SourceResult<
 fn export_paged(
     document: &PagedDocument,
     config: &CompileConfig,
+    warnings: &mut EcoVec<SourceDiagnostic>,
 ) -> SourceResult<Vec<Output>> {
     match config.output_format {
-        OutputFormat::Pdf => {
-            export_pdf(document, config).map(|()| vec![config.output.clone()])
-        }
+        OutputFormat::Pdf => export_pdf(document, config, warnings),
         OutputFormat::Png => {
             export_image(document, config, ImageExportFormat::Png).at(Span::detached())
         }
@@ -354,8 +386,15 @@ fn export_paged(
     }
 }
 
-/// Export to a PDF.
-fn export_pdf(document: &PagedDocument, config: &CompileConfig) -> SourceResult<()> {
+/// Export to one or multiple PDFs.
+///
+/// If the output path contains a page number template, one PDF is written per
+/// exported page.
+fn export_pdf(
+    document: &PagedDocument,
+    config: &CompileConfig,
+    warnings: &mut EcoVec<SourceDiagnostic>,
+) -> SourceResult<Vec<Output>> {
     // If the timestamp is provided through the CLI, use UTC suffix,
     // else, use the current local time and timezone.
     let timestamp = match config.creation_timestamp {
@@ -371,20 +410,75 @@ fn export_pdf(document: &PagedDocument, config: &CompileConfig) -> SourceResult<
         }
     };
 
-    let options = PdfOptions {
+    let options = |page_ranges: Option<PageRanges>| PdfOptions {
         ident: Smart::Auto,
         timestamp,
-        page_ranges: config.pages.clone(),
+        page_ranges,
         standards: config.pdf_standards.clone(),
         tagged: config.tagged,
+        deny_restricted_fonts: config.deny_restricted_fonts,
+    };
+
+    let Some(template) = &config.pdf_template else {
+        let Warned { output, warnings: pdf_warnings } =
+            typst_pdf::pdf_with_warnings(document, &options(config.pages.clone()));
+        warnings.extend(pdf_warnings);
+        write_pdf(&config.output, &output?)?;
+        return Ok(vec![config.output.clone()]);
     };
-    let buffer = typst_pdf::pdf(document, &options)?;
-    config
-        .output
-        .write(&buffer)
+
+    let exported_pages = (0..document.pages.len())
+        .filter(|&i| {
+            config.pages.as_ref().is_none_or(|exported_page_ranges| {
+                exported_page_ranges.includes_page_index(i)
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let results: Vec<_> = exported_pages
+        .par_iter()
+        .map(|&i| {
+            let path = output_template::format(template, i + 1, document.pages.len());
+            let path = PathBuf::from(path);
+
+            // As for images, skip pages that haven't changed since the last
+            // export in watch mode.
+            if config.watching
+                && config.export_cache.is_cached(i, &document.pages[i])
+                && path.exists()
+            {
+                return (Ok(Output::Path(path)), EcoVec::new());
+            }
+
+            let number = NonZeroUsize::new(i + 1);
+            let ranges = PageRanges::new(vec![number..=number]);
+            let Warned { output, warnings: page_warnings } =
+                typst_pdf::pdf_with_warnings(document, &options(Some(ranges)));
+            let result = output.and_then(|buffer| {
+                let output = Output::Path(path);
+                write_pdf(&output, &buffer)?;
+                Ok(output)
+            });
+            (result, page_warnings)
+        })
+        .collect();
+
+    // The same font may be used on multiple pages, so we deduplicate.
+    for warning in results.iter().flat_map(|(_, page_warnings)| page_warnings) {
+        if !warnings.contains(warning) {
+            warnings.push(warning.clone());
+        }
+    }
+
+    results.into_iter().map(|(result, _)| result).collect()
+}
+
+/// Write an exported PDF to the given output.
+fn write_pdf(output: &Output, buffer: &[u8]) -> SourceResult<()> {
+    output
+        .write(buffer)
         .map_err(|err| eco_format!("failed to write PDF file ({err})"))
-        .at(Span::detached())?;
-    Ok(())
+        .at(Span::detached())
 }
 
 /// Convert [`chrono::DateTime`] to [`Datetime`]
@@ -24,6 +24,37 @@ fn test_compile_pdf() {
     project.read("hello.pdf").must_start_with("%PDF").must_contain(title);
 }
 
+#[test]
+fn test_compile_pdf_per_page() {
+    let project = tempfs();
+    let main = project.write(
+        "main.typ",
+        "= Alpha
+         #pdf.attach(\"data.txt\")
+         #pagebreak()
+         == Omega",
+    );
+    project.write("data.txt", "Hello");
+    exec()
+        .arg("compile")
+        .arg(&main)
+        .arg(project.resolve("page-{p}.pdf"))
+        .arg("--no-pdf-tags")
+        .must_succeed();
+
+    // Each file only has the bookmarks and attachments of its own page.
+    project
+        .read("page-1.pdf")
+        .must_contain("Alpha")
+        .must_contain("data.txt")
+        .must_not_contain("Omega");
+    project
+        .read("page-2.pdf")
+        .must_contain("Omega")
+        .must_not_contain("Alpha")
+        .must_not_contain("data.txt");
+}
+
 #[test]
 fn test_eval() {
     let output = exec().arg("eval").arg("1+2").must_succeed();
@@ -282,6 +313,12 @@ impl<T: AsRef<[u8]>> Stream<T> {
         self
     }
 
+    #[track_caller]
+    fn must_not_contain(&self, data: impl Debug + AsRef<[u8]>) -> &Self {
+        assert!(!self.contains(data.as_ref()), "{self:?} contained {data:?}",);
+        self
+    }
+
     #[track_caller]
     fn must_start_with(&self, data: impl Debug + AsRef<[u8]>) -> &Self {
         assert!(
@@ -7,7 +7,7 @@ use typst_library::introspection::{
     DocumentPosition, InnerHtmlPosition, Introspector, Location, Tag,
 };
 use typst_library::layout::{Frame, FrameItem, Point};
-use typst_library::model::{Destination, LinkElem};
+use typst_library::model::{Destination, LinkElem, heading_ids};
 use typst_utils::PicoStr;
 
 use crate::{HtmlElement, HtmlNode, attr, tag};
@@ -32,6 +32,10 @@ pub fn introspect_frame_links(frame: &Frame, targets: &mut FxHashSet<Location>)
 
 /// Attaches IDs to nodes produced by link targets to make them linkable.
 ///
+/// Unlabelled headings always receive an ID derived from their text, even if
+/// nothing in the document links to them, so that they can be linked to from
+/// the outside.
+///
 /// May produce `<span>`s for link targets that turned into text nodes or no
 /// nodes at all. See the [`LinkElem`] documentation for more details.
 pub fn identify_link_targets(
@@ -52,6 +56,13 @@ pub fn identify_link_targets(
             }),
     );
 
+    // Headings are always linkable with stable IDs. These must not collide
+    // with IDs that were explicitly set on elements.
+    let mut explicit = FxHashSet::default();
+    collect_explicit_ids(&root.children, &mut explicit);
+    let slugs = heading_ids(introspector, &explicit);
+    targets.extend(slugs.keys().copied());
+
     if targets.is_empty() {
         // Nothing to do.
         return;
@@ -62,7 +73,7 @@ pub fn identify_link_targets(
     traverse(
         &mut work,
         &targets,
-        &mut Identificator::new(introspector),
+        &mut Identificator::new(introspector, slugs, explicit),
         &mut root.children,
     );
 
@@ -71,6 +82,18 @@ pub fn identify_link_targets(
     introspector.set_html_ids(work.ids);
 }
 
+/// Collects the IDs that were explicitly set on elements.
+fn collect_explicit_ids(nodes: &[HtmlNode], ids: &mut FxHashSet<EcoString>) {
+    for node in nodes {
+        if let HtmlNode::Element(element) = node {
+            if let Some(id) = element.attrs.get(attr::id) {
+                ids.insert(id.clone());
+            }
+            collect_explicit_ids(&element.children, ids);
+        }
+    }
+}
+
 /// Traverses a list of nodes.
 fn traverse(
     work: &mut Work,
@@ -96,9 +119,9 @@ fn traverse(
             // that is still in our queue. If so, that means the element
             // produced no nodes and we need to insert an empty span.
             HtmlNode::Tag(Tag::End(loc, _, _)) => {
-                work.remove(*loc, |label| {
+                work.remove(*loc, |loc, label| {
                     let mut element = HtmlElement::new(tag::span);
-                    let id = identificator.assign(&mut element, label);
+                    let id = identificator.assign(&mut element, loc, label);
                     nodes.insert(i + 1, HtmlNode::Element(element));
                     id
                 });
@@ -107,17 +130,17 @@ fn traverse(
             // When visiting an element and the queue is non-empty, we assign an
             // ID. Then, we traverse its children.
             HtmlNode::Element(element) => {
-                work.drain(|label| identificator.assign(element, label));
+                work.drain(|loc, label| identificator.assign(element, loc, label));
                 traverse(work, targets, identificator, &mut element.children);
             }
 
             // When visiting text and the queue is non-empty, we generate a span
             // and assign an ID.
             HtmlNode::Text(..) => {
-                work.drain(|label| {
+                work.drain(|loc, label| {
                     let mut element =
                         HtmlElement::new(tag::span).with_children(eco_vec![node.clone()]);
-                    let id = identificator.assign(&mut element, label);
+                    let id = identificator.assign(&mut element, loc, label);
                     *node = HtmlNode::Element(element);
                     id
                 });
@@ -126,8 +149,11 @@ fn traverse(
             // When visiting a frame and the queue is non-empty, we assign an
             // ID to it (will be added to the resulting SVG element).
             HtmlNode::Frame(frame) => {
-                work.drain(|label| {
-                    frame.id.get_or_insert_with(|| identificator.identify(label)).clone()
+                work.drain(|loc, label| {
+                    frame
+                        .id
+                        .get_or_insert_with(|| identificator.identify(loc, label))
+                        .clone()
                 });
                 traverse_frame(
                     work,
@@ -160,7 +186,7 @@ fn traverse_frame(
                         identificator.introspector.position(loc)
                     && let Some(InnerHtmlPosition::Frame(point)) = position.details()
                 {
-                    let id = identificator.identify(elem.label());
+                    let id = identificator.identify(loc, elem.label());
                     work.ids.insert(loc, id.clone());
                     link_points.push((*point, id));
                 }
@@ -197,9 +223,9 @@ impl Work {
     /// If one or multiple elements are in need of an ID, calls `f` to generate
     /// an ID and apply it to the current node with `f`, and then establishes a
     /// mapping from the elements' locations to that ID.
-    fn drain(&mut self, f: impl FnOnce(Option<Label>) -> EcoString) {
-        if let Some(&(_, label)) = self.queue.front() {
-            let id = f(label);
+    fn drain(&mut self, f: impl FnOnce(Location, Option<Label>) -> EcoString) {
+        if let Some(&(loc, label)) = self.queue.front() {
+            let id = f(loc, label);
             for (loc, _) in self.queue.drain(..) {
                 self.ids.insert(loc, id.clone());
             }
@@ -207,10 +233,14 @@ impl Work {
     }
 
     /// Similar to `drain`, but only for a specific given location.
-    fn remove(&mut self, loc: Location, f: impl FnOnce(Option<Label>) -> EcoString) {
+    fn remove(
+        &mut self,
+        loc: Location,
+        f: impl FnOnce(Location, Option<Label>) -> EcoString,
+    ) {
         if let Some(i) = self.queue.iter().position(|&(l, _)| l == loc) {
             let (_, label) = self.queue.remove(i).unwrap();
-            let id = f(label);
+            let id = f(loc, label);
             self.ids.insert(loc, id.clone());
         }
     }
@@ -221,29 +251,47 @@ struct Identificator<'a> {
     introspector: &'a Introspector,
     loc_counter: usize,
     label_counter: FxHashMap<Label, usize>,
+    /// Stable IDs for unlabelled headings, derived from their text.
+    slugs: FxHashMap<Location, EcoString>,
+    /// The values of `slugs` and the explicitly set IDs, which generated IDs
+    /// must not collide with.
+    reserved: FxHashSet<EcoString>,
 }
 
 impl<'a> Identificator<'a> {
     /// Creates a new identificator.
-    fn new(introspector: &'a Introspector) -> Self {
+    fn new(
+        introspector: &'a Introspector,
+        slugs: FxHashMap<Location, EcoString>,
+        mut reserved: FxHashSet<EcoString>,
+    ) -> Self {
+        reserved.extend(slugs.values().cloned());
         Self {
             introspector,
             loc_counter: 0,
             label_counter: FxHashMap::default(),
+            slugs,
+            reserved,
         }
     }
 
     /// Assigns an ID to an element or reuses an existing ID.
-    fn assign(&mut self, element: &mut HtmlElement, label: Option<Label>) -> EcoString {
+    fn assign(
+        &mut self,
+        element: &mut HtmlElement,
+        loc: Location,
+        label: Option<Label>,
+    ) -> EcoString {
         element.attrs.get(attr::id).cloned().unwrap_or_else(|| {
-            let id = self.identify(label);
+            let id = self.identify(loc, label);
             element.attrs.push_front(attr::id, id.clone());
             id
         })
     }
 
-    /// Generates an ID, potentially based on a label.
-    fn identify(&mut self, label: Option<Label>) -> EcoString {
+    /// Generates an ID for the element with the given location, potentially
+    /// based on a label or, for headings, on the element's text.
+    fn identify(&mut self, loc: Location, label: Option<Label>) -> EcoString {
         if let Some(label) = label {
             let resolved = label.resolve();
             let text = resolved.as_str();
@@ -254,12 +302,16 @@ impl<'a> Identificator<'a> {
 
                 let counter = self.label_counter.entry(label).or_insert(0);
                 *counter += 1;
-                return disambiguate(self.introspector, text, counter);
+                return disambiguate(self.introspector, &self.reserved, text, counter);
             }
         }
 
+        if let Some(slug) = self.slugs.get(&loc) {
+            return slug.clone();
+        }
+
         self.loc_counter += 1;
-        disambiguate(self.introspector, "loc", &mut self.loc_counter)
+        disambiguate(self.introspector, &self.reserved, "loc", &mut self.loc_counter)
     }
 }
 
@@ -275,17 +327,19 @@ fn can_use_label_as_id(label: &str) -> bool {
 }
 
 /// Disambiguates `text` with the suffix `-{counter}`, while ensuring that this
-/// does not result in a collision with an existing label.
+/// does not result in a collision with an existing label or a reserved ID.
 fn disambiguate(
     introspector: &Introspector,
+    reserved: &FxHashSet<EcoString>,
     text: &str,
     counter: &mut usize,
 ) -> EcoString {
     loop {
         let disambiguated = eco_format!("{text}-{counter}");
-        if PicoStr::get(&disambiguated)
-            .and_then(Label::new)
-            .is_some_and(|label| introspector.label_count(label) > 0)
+        if reserved.contains(&disambiguated)
+            || PicoStr::get(&disambiguated)
+                .and_then(Label::new)
+                .is_some_and(|label| introspector.label_count(label) > 0)
         {
             *counter += 1;
         } else {
@@ -0,0 +1,217 @@
+use typst::layout::{Dir, PagedDocument, Rect};
+
+use crate::select::{TextCluster, text_clusters};
+
+const SHY: char = '\u{ad}';
+
+/// Find all occurrences of a piece of text in a compiled document.
+///
+/// Returns the zero-based page index and area of each match. A match that
+/// spans multiple lines yields one rectangle per line. The search operates
+/// on the logical text of the document: Ligatures are matched by their
+/// constituent characters, right-to-left text is matched in reading order,
+/// words hyphenated across lines are matched as if they were not broken, and
+/// a line break counts as a single space.
+pub fn find_text(document: &PagedDocument, needle: &str) -> Vec<(usize, Rect)> {
+    let mut matches = vec![];
+    if needle.is_empty() {
+        return matches;
+    }
+
+    for (i, page) in document.pages.iter().enumerate() {
+        let clusters = text_clusters(&page.frame);
+        let lines = line_indices(&clusters);
+        let (haystack, owners) = logical_text(&clusters, &lines);
+        for (start, m) in haystack.match_indices(needle) {
+            let mut hit = owners[start..start + m.len()].iter().flatten().copied();
+            let Some(first) = hit.next() else { continue };
+
+            let mut rect = clusters[first].rect;
+            let mut prev = first;
+            for k in hit {
+                if k == prev {
+                    continue;
+                }
+                let cluster = &clusters[k];
+                if lines[k] != lines[prev] {
+                    matches.push((i, rect));
+                    rect = cluster.rect;
+                } else {
+                    rect = union(rect, cluster.rect);
+                }
+                prev = k;
+            }
+            matches.push((i, rect));
+        }
+    }
+
+    matches
+}
+
+/// Assign each cluster the index of the line it is on.
+fn line_indices(clusters: &[TextCluster]) -> Vec<usize> {
+    let mut lines = Vec::with_capacity(clusters.len());
+    let mut line = 0;
+    for (i, cluster) in clusters.iter().enumerate() {
+        if i > 0 && is_new_line(&clusters[i - 1], cluster) {
+            line += 1;
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+/// Determine the order in which the clusters are read.
+///
+/// Lines without right-to-left text are already in reading order. In a line
+/// with right-to-left text possibly mixed with left-to-right words or numbers,
+/// the clusters are ordered by their position in the source, since span
+/// numbers increase in source order within a file. If that is not known for
+/// all clusters on the line, runs of right-to-left clusters are reversed
+/// instead, which is correct for right-to-left words in left-to-right text.
+fn logical_order(clusters: &[TextCluster], lines: &[usize]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..clusters.len()).collect();
+    for line in order.chunk_by_mut(|&a, &b| lines[a] == lines[b]) {
+        if !line.iter().any(|&k| clusters[k].dir == Dir::RTL) {
+            continue;
+        }
+
+        let file = clusters[line[0]].span.0.id();
+        if file.is_some() && line.iter().all(|&k| clusters[k].span.0.id() == file) {
+            line.sort_by_key(|&k| {
+                let (span, offset) = clusters[k].span;
+                (span.into_raw(), offset)
+            });
+        } else {
+            for run in line.chunk_by_mut(|&a, &b| clusters[a].dir == clusters[b].dir) {
+                if clusters[run[0]].dir == Dir::RTL {
+                    run.reverse();
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Reconstruct the logical text of the clusters on a page.
+///
+/// Also returns, for each byte of the text, the index of the cluster it
+/// stems from, or `None` for spaces inserted at line breaks.
+fn logical_text(
+    clusters: &[TextCluster],
+    lines: &[usize],
+) -> (String, Vec<Option<usize>>) {
+    let mut text = String::new();
+    let mut owners = vec![];
+    let mut prev: Option<usize> = None;
+
+    for i in logical_order(clusters, lines) {
+        let cluster = &clusters[i];
+
+        // Soft hyphens are only visible because the line was broken at them,
+        // so they do not form part of the logical text.
+        let soft = cluster.text.chars().all(|c| c == SHY);
+
+        if let Some(prev) = prev
+            && lines[prev] != lines[i]
+            && !clusters[prev].text.chars().all(|c| c == SHY)
+            && !clusters[prev].text.ends_with(char::is_whitespace)
+        {
+            text.push(' ');
+            owners.push(None);
+        }
+
+        if !soft {
+            text.push_str(&cluster.text);
+            owners.extend(std::iter::repeat_n(Some(i), cluster.text.len()));
+        }
+
+        prev = Some(i);
+    }
+
+    (text, owners)
+}
+
+/// Whether `next` starts a new line with respect to `prev`, given that both
+/// are in visual order.
+fn is_new_line(prev: &TextCluster, next: &TextCluster) -> bool {
+    next.rect.min.y >= prev.rect.max.y
+        || next.rect.max.y <= prev.rect.min.y
+        || next.rect.min.x < prev.rect.min.x
+}
+
+/// The smallest rectangle containing both rectangles.
+fn union(a: Rect, b: Rect) -> Rect {
+    Rect::new(a.min.min(b.min), a.max.max(b.max))
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::layout::{PagedDocument, Rect};
+
+    use super::find_text;
+    use crate::tests::TestWorld;
+
+    #[track_caller]
+    fn test(text: &str, needle: &str, expected: usize) -> Vec<(usize, Rect)> {
+        let world = TestWorld::new(text);
+        let doc: PagedDocument = typst::compile(&world).output.unwrap();
+        let found = find_text(&doc, needle);
+        assert_eq!(found.len(), expected);
+        found
+    }
+
+    #[test]
+    fn test_find_text() {
+        test("Hello World, hello world", "world", 1);
+        test("Hello World", "lo Wo", 1);
+        test("Hello World", "Typst", 0);
+        test("fine office", "fi", 2);
+    }
+
+    #[test]
+    fn test_find_text_across_lines() {
+        let text = "#set page(width: 40pt, height: auto, margin: 0pt)\nHello World";
+        let found = test(text, "Hello World", 2);
+        assert_eq!(found[0].0, 0);
+        assert!(found[0].1.max.y <= found[1].1.min.y);
+    }
+
+    #[test]
+    fn test_find_text_across_pages() {
+        let found = test("A #pagebreak() A", "A", 2);
+        assert_eq!(found[0].0, 0);
+        assert_eq!(found[1].0, 1);
+    }
+
+    #[test]
+    fn test_find_text_rtl() {
+        let text = "#set text(lang: \"he\")\nשלום *עולם*";
+        test(text, "שלום", 1);
+        test(text, "שלום עולם", 1);
+        test(text, "םולש", 0);
+    }
+
+    #[test]
+    fn test_find_text_rtl_mixed() {
+        let text = "#set text(lang: \"he\")\nשלום Typst 2024 עולם";
+        test(text, "שלום Typst 2024 עולם", 1);
+        test(text, "שלום Typst", 1);
+        test(text, "2024 עולם", 1);
+        test(text, "עולם 2024", 0);
+    }
+
+    #[test]
+    fn test_find_text_hyphenated() {
+        let text = "#set page(width: 36pt, height: auto, margin: 0pt)\n\
+                    #set text(size: 8pt, hyphenate: false)\n\
+                    Type-?setting";
+        let found = test(text, "Typesetting", 2);
+        assert_eq!(found[0].0, 0);
+        assert_eq!(found[1].0, 0);
+        assert!(found[0].1.min.x.approx_empty());
+        assert!(found[1].1.min.x.approx_empty());
+        assert!(found[0].1.max.y <= found[1].1.min.y);
+        test(text, "Type-", 0);
+    }
+}
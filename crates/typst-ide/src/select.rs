@@ -0,0 +1,160 @@
+use ecow::EcoString;
+use typst::layout::{Abs, Dir, Frame, FrameItem, Point, Rect, Transform};
+use typst::syntax::Span;
+use typst::text::TextItem;
+
+/// A cluster of characters that was laid out as one or multiple glyphs.
+///
+/// Clusters are the smallest unit that can be selected in the output. A
+/// ligature, for instance, forms a single cluster spanning multiple
+/// characters.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TextCluster {
+    /// The plain text of the cluster.
+    pub text: EcoString,
+    /// The axis-aligned bounding box of the cluster, in the coordinate system
+    /// of the frame passed to [`text_clusters`].
+    pub rect: Rect,
+    /// The baseline of the text run the cluster belongs to, in the same
+    /// coordinate system as `rect`.
+    pub baseline: Abs,
+    /// The source code location of the cluster's first glyph.
+    pub span: (Span, u16),
+    /// The direction of the text run the cluster belongs to. Clusters of a
+    /// right-to-left run are still yielded from left to right, so they must
+    /// be reversed to obtain the run's logical order.
+    pub dir: Dir,
+}
+
+/// Collect the geometry of all text clusters in a frame.
+///
+/// The clusters are yielded in the order of the frame's items and, within a
+/// text run, from left to right. For flowing text, this mostly matches
+/// reading order. It does not for placed content, footnotes, headers and
+/// footers, or math, and within right-to-left runs (see
+/// [`TextCluster::dir`]). Callers that need logical order must sort the
+/// clusters themselves.
+///
+/// The rectangles span from the font's ascender to its descender
+/// vertically and cover the clusters' advances horizontally. They are
+/// suitable for drawing selection and highlighting overlays on top of a
+/// rendered page.
+pub fn text_clusters(frame: &Frame) -> Vec<TextCluster> {
+    let mut clusters = vec![];
+    collect_clusters(&mut clusters, frame, Transform::identity());
+    clusters
+}
+
+/// Recursively collect the text clusters of a frame.
+fn collect_clusters(clusters: &mut Vec<TextCluster>, frame: &Frame, ts: Transform) {
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                collect_clusters(clusters, &group.frame, ts);
+            }
+            FrameItem::Text(text) => collect_text(clusters, text, *pos, ts),
+            _ => {}
+        }
+    }
+}
+
+/// Collect the clusters of a single text run.
+fn collect_text(
+    clusters: &mut Vec<TextCluster>,
+    text: &TextItem,
+    pos: Point,
+    ts: Transform,
+) {
+    let metrics = text.font.metrics();
+    let top = pos.y - metrics.ascender.at(text.size);
+    let bottom = pos.y - metrics.descender.at(text.size);
+
+    // In right-to-left runs, glyphs are stored in visual order, so their text
+    // ranges decrease. Runs with a single cluster fall back to the language's
+    // direction.
+    let dir = match (text.glyphs.first(), text.glyphs.last()) {
+        (Some(first), Some(last)) if first.range != last.range => {
+            if first.range.start > last.range.start { Dir::RTL } else { Dir::LTR }
+        }
+        _ => text.lang.dir(),
+    };
+
+    let mut x = pos.x;
+    let mut i = 0;
+    while i < text.glyphs.len() {
+        // Glyphs that share the same text range belong to the same cluster,
+        // e.g. a base character with a separately positioned mark.
+        let range = text.glyphs[i].range();
+        let start = x;
+        let mut j = i;
+        while j < text.glyphs.len() && text.glyphs[j].range() == range {
+            x += text.glyphs[j].x_advance.at(text.size);
+            j += 1;
+        }
+
+        let rect =
+            transform_rect(Rect::new(Point::new(start, top), Point::new(x, bottom)), ts);
+        clusters.push(TextCluster {
+            text: text.text[range].into(),
+            rect,
+            baseline: Point::new(start, pos.y).transform(ts).y,
+            span: text.glyphs[i].span,
+            dir,
+        });
+
+        i = j;
+    }
+}
+
+/// Compute the bounding box of a transformed rectangle.
+fn transform_rect(rect: Rect, ts: Transform) -> Rect {
+    let corners = [
+        rect.min,
+        Point::new(rect.max.x, rect.min.y),
+        Point::new(rect.min.x, rect.max.y),
+        rect.max,
+    ]
+    .map(|p| p.transform(ts));
+
+    let mut min = corners[0];
+    let mut max = corners[0];
+    for p in &corners[1..] {
+        min = min.min(*p);
+        max = max.max(*p);
+    }
+    Rect::new(min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::layout::{Dir, PagedDocument};
+
+    use super::text_clusters;
+    use crate::tests::TestWorld;
+
+    #[test]
+    fn test_text_clusters() {
+        let world = TestWorld::new("Hello *World*");
+        let doc: PagedDocument = typst::compile(&world).output.unwrap();
+        let clusters = text_clusters(&doc.pages[0].frame);
+        let text: String = clusters.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(text, "Hello World");
+        for pair in clusters.windows(2) {
+            assert!(pair[0].rect.min.x < pair[1].rect.min.x);
+            assert!(pair[0].rect.max.y > pair[0].rect.min.y);
+        }
+    }
+
+    #[test]
+    fn test_text_clusters_rtl() {
+        let world = TestWorld::new("#set text(lang: \"he\")\nשלום");
+        let doc: PagedDocument = typst::compile(&world).output.unwrap();
+        let clusters = text_clusters(&doc.pages[0].frame);
+        assert!(clusters.iter().all(|c| c.dir == Dir::RTL));
+        let text: String = clusters.iter().rev().map(|c| c.text.as_str()).collect();
+        assert_eq!(text, "שלום");
+    }
+}
@@ -15,7 +15,7 @@ use typst_library::introspection::{
 use typst_library::layout::{
     Abs, AlignElem, Alignment, Axes, BlockElem, ColbreakElem, FixedAlignment, FlushElem,
     Fr, Fragment, Frame, FrameParent, Inherit, PagebreakElem, PlaceElem, PlacementScope,
-    Ratio, Region, Regions, Rel, Size, Sizing, Spacing, VElem,
+    Ratio, RedactElem, Region, Regions, Rel, Size, Sizing, Spacing, VElem,
 };
 use typst_library::model::ParElem;
 use typst_library::routines::{Pair, Routines};
@@ -77,7 +77,10 @@ impl<'a> Collector<'a, '_, '_> {
     fn run_block(mut self) -> SourceResult<Vec<Child<'a>>> {
         for &(child, styles) in self.children {
             if let Some(elem) = child.to_packed::<TagElem>() {
-                self.output.push(Child::Tag(&elem.tag));
+                // Elements within redacted content leave no traces.
+                if styles.get_ref(RedactElem::redacted).is_none() {
+                    self.output.push(Child::Tag(&elem.tag));
+                }
             } else if let Some(elem) = child.to_packed::<VElem>() {
                 self.v(elem, styles);
             } else if let Some(elem) = child.to_packed::<ParElem>() {
@@ -127,17 +130,21 @@ impl<'a> Collector<'a, '_, '_> {
         )?
         .into_frames();
 
-        for (c, _) in &self.children[..start] {
+        for (c, styles) in &self.children[..start] {
             let elem = c.to_packed::<TagElem>().unwrap();
-            self.output.push(Child::Tag(&elem.tag));
+            if styles.get_ref(RedactElem::redacted).is_none() {
+                self.output.push(Child::Tag(&elem.tag));
+            }
         }
 
         let leading = styles.resolve(ParElem::leading);
         self.lines(lines, leading, styles);
 
-        for (c, _) in &self.children[end..] {
+        for (c, styles) in &self.children[end..] {
             let elem = c.to_packed::<TagElem>().unwrap();
-            self.output.push(Child::Tag(&elem.tag));
+            if styles.get_ref(RedactElem::redacted).is_none() {
+                self.output.push(Child::Tag(&elem.tag));
+            }
         }
 
         Ok(self.output)
@@ -651,7 +658,8 @@ impl PlacedChild<'_> {
                 )
             })?;
 
-            if self.float {
+            // Redacted floats have no tags that could act as their parent.
+            if self.float && self.styles.get_ref(RedactElem::redacted).is_none() {
                 frame.set_parent(FrameParent::new(
                     self.elem.location().unwrap(),
                     Inherit::Yes,
@@ -31,17 +31,23 @@ use super::{
 ///
 /// To lay out the in-flow contents of individual subregions, the composer
 /// invokes [distribution](distribute).
+///
+/// If a `column_height` is given, the columns are shortened to it while page
+/// insertions are still laid out with respect to the full region. This is used
+/// for column balancing.
 pub fn compose(
     engine: &mut Engine,
     work: &mut Work,
     config: &Config,
     locator: Locator,
     regions: Regions,
+    column_height: Option<Abs>,
 ) -> SourceResult<Frame> {
     Composer {
         engine,
         config,
         page_base: regions.base(),
+        column_height,
         column: 0,
         page_insertions: Insertions::default(),
         column_insertions: Insertions::default(),
@@ -66,6 +72,7 @@ pub struct Composer<'a, 'b, 'x, 'y> {
     pub config: &'x Config<'x>,
     column: usize,
     page_base: Size,
+    column_height: Option<Abs>,
     page_insertions: Insertions<'a, 'b>,
     column_insertions: Insertions<'a, 'b>,
     // These are here because they have to survive relayout (we could lose the
@@ -112,7 +119,9 @@ impl<'a, 'b> Composer<'a, 'b, '_, '_> {
         }
 
         // Create a backlog for multi-column layout.
-        let column_height = regions.size.y;
+        let column_height = self
+            .column_height
+            .map_or(regions.size.y, |height| height.min(regions.size.y));
         let backlog: Vec<_> = std::iter::once(&column_height)
             .chain(regions.backlog)
             .flat_map(|&h| std::iter::repeat_n(h, self.config.columns.count))
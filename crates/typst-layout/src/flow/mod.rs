@@ -73,6 +73,7 @@ pub fn layout_fragment(
         regions,
         NonZeroUsize::ONE,
         Rel::zero(),
+        false,
     )
 }
 
@@ -101,6 +102,7 @@ pub fn layout_columns(
         regions,
         elem.count.get(styles),
         elem.gutter.resolve(styles),
+        elem.balance.get(styles),
     )
 }
 
@@ -120,6 +122,7 @@ fn layout_fragment_impl(
     regions: Regions,
     columns: NonZeroUsize,
     column_gutter: Rel<Abs>,
+    balance: bool,
 ) -> SourceResult<Fragment> {
     if !regions.size.x.is_finite() && regions.expand.x {
         bail!(content.span(), "cannot expand into infinite width");
@@ -161,6 +164,7 @@ fn layout_fragment_impl(
         regions,
         columns,
         column_gutter,
+        balance,
         kind.into(),
     )
 }
@@ -196,10 +200,11 @@ pub fn layout_flow<'a>(
     mut regions: Regions,
     columns: NonZeroUsize,
     column_gutter: Rel<Abs>,
+    balance: bool,
     mode: FlowMode,
 ) -> SourceResult<Fragment> {
     // Prepare configuration that is shared across the whole flow.
-    let config = configuration(shared, regions, columns, column_gutter, mode);
+    let config = configuration(shared, regions, columns, column_gutter, balance, mode);
 
     // Collect the elements into pre-processed children. These are much easier
     // to handle than the raw elements.
@@ -219,7 +224,33 @@ pub fn layout_flow<'a>(
 
     // This loop runs once per region produced by the flow layout.
     loop {
-        let frame = compose(engine, &mut work, &config, locator.next(&()), regions)?;
+        let region_locator = locator.next(&());
+        let checkpoint = config.columns.balance.then(|| work.clone());
+        let mut frame = compose(
+            engine,
+            &mut work,
+            &config,
+            region_locator.relayout(),
+            regions,
+            None,
+        )?;
+
+        // If everything fit into this region, it is the final one and we can
+        // try to balance its columns.
+        if let Some(checkpoint) = checkpoint
+            && work.done()
+        {
+            frame = balance_columns(
+                engine,
+                &mut work,
+                checkpoint,
+                &config,
+                region_locator,
+                regions,
+                frame,
+            )?;
+        }
+
         finished.push(frame);
 
         // Terminate the loop when everything is processed, though draining the
@@ -234,12 +265,80 @@ pub fn layout_flow<'a>(
     Ok(Fragment::frames(finished))
 }
 
+/// Balances the columns of the final region of a flow.
+///
+/// Searches for the smallest column height at which the remaining work still
+/// fits into the region's columns and recomposes the region with it. Falls
+/// back to the unbalanced `frame` if no such height is found.
+#[allow(clippy::too_many_arguments)]
+fn balance_columns<'a, 'b>(
+    engine: &mut Engine,
+    work: &mut Work<'a, 'b>,
+    checkpoint: Work<'a, 'b>,
+    config: &Config,
+    locator: Locator,
+    regions: Regions,
+    frame: Frame,
+) -> SourceResult<Frame> {
+    /// The maximum number of recompositions. Twelve halvings narrow the search
+    /// down to the precision for regions up to 2000pt (about 70cm) tall, so
+    /// this only cuts the search short for very tall regions.
+    const MAX_ATTEMPTS: usize = 12;
+
+    // The precision at which the search for the column height stops. Half a
+    // point is hardly visible and saves a few recompositions compared to
+    // searching for the exact height.
+    let precision = Abs::pt(0.5);
+
+    if config.columns.count == 1 {
+        return Ok(frame);
+    }
+
+    // In an unbounded region, everything ends up in the first column, so its
+    // height is an upper bound for the balanced height.
+    let height = if regions.size.y.is_finite() { regions.size.y } else { frame.height() };
+
+    // Provide a followup region so that content which doesn't fit into the
+    // shortened columns is deferred instead of being forced into the last
+    // column. This lets us detect whether a trial height is sufficient.
+    let backlog = [regions.full];
+    let pod = Regions { backlog: &backlog, last: None, ..regions };
+
+    let mut lo = Abs::zero();
+    let mut hi = height;
+    let mut best = None;
+    for _ in 0..MAX_ATTEMPTS {
+        if hi - lo < precision {
+            break;
+        }
+
+        // Only the columns are shortened. The region itself keeps its full
+        // height, so that page insertions like footnotes and bottom floats
+        // still end up at its bottom.
+        let mid = (lo + hi) / 2.0;
+        let mut trial = checkpoint.clone();
+        let output =
+            compose(engine, &mut trial, config, locator.relayout(), pod, Some(mid))?;
+        if trial.done() {
+            hi = mid;
+            best = Some((trial, output));
+        } else {
+            lo = mid;
+        }
+    }
+
+    let Some((trial, output)) = best else { return Ok(frame) };
+    *work = trial;
+    Ok(output)
+}
+
 /// Determine the flow's configuration.
 fn configuration<'x>(
     shared: StyleChain<'x>,
     regions: Regions,
     columns: NonZeroUsize,
     column_gutter: Rel<Abs>,
+    balance: bool,
     mode: FlowMode,
 ) -> Config<'x> {
     Config {
@@ -254,7 +353,7 @@ fn configuration<'x>(
             let gutter = column_gutter.relative_to(regions.base().x);
             let width = (regions.size.x - gutter * (count - 1) as f64) / count as f64;
             let dir = shared.resolve(TextElem::dir);
-            ColumnConfig { count, width, gutter, dir }
+            ColumnConfig { count, width, gutter, dir, balance }
         },
         footnote: FootnoteConfig {
             separator: shared
@@ -391,6 +490,8 @@ struct ColumnConfig {
     /// The horizontal direction in which columns progress. Defined by
     /// `text.dir`.
     dir: Dir,
+    /// Whether to balance the column heights in the final region.
+    balance: bool,
 }
 
 /// Configuration of line numbers.
@@ -2,7 +2,8 @@ use typst_library::diag::warning;
 use typst_library::foundations::{Packed, Resolve};
 use typst_library::introspection::{SplitLocator, Tag, TagElem};
 use typst_library::layout::{
-    Abs, BoxElem, Dir, Fr, Frame, HElem, InlineElem, InlineItem, Sizing, Spacing,
+    Abs, BoxElem, Dir, Fr, Frame, HElem, InlineElem, InlineItem, RedactElem, Sizing,
+    Spacing,
 };
 use typst_library::routines::Pair;
 use typst_library::text::{
@@ -232,7 +233,10 @@ pub fn collect<'a>(
                 collector.push_item(Item::Frame(frame));
             }
         } else if let Some(elem) = child.to_packed::<TagElem>() {
-            collector.push_item(Item::Tag(&elem.tag));
+            // Elements within redacted content leave no traces.
+            if styles.get_ref(RedactElem::redacted).is_none() {
+                collector.push_item(Item::Tag(&elem.tag));
+            }
         } else {
             // Non-paragraph inline layout should never trigger this since it
             // only won't be triggered if we see any non-inline content.
@@ -1,6 +1,9 @@
 use typst_library::foundations::StyleChain;
-use typst_library::layout::{Abs, Fragment, Frame, FrameItem, HideElem, Point, Sides};
+use typst_library::layout::{
+    Abs, Fragment, Frame, FrameItem, HideElem, Point, RedactElem, Sides,
+};
 use typst_library::model::{Destination, LinkElem, ParElem};
+use typst_library::visualize::Paint;
 
 /// Frame-level modifications resulting from styles that do not impose any
 /// layout structure.
@@ -16,6 +19,7 @@ use typst_library::model::{Destination, LinkElem, ParElem};
 ///
 /// Currently existing frame modifiers are:
 /// - `HideElem::hidden`
+/// - `RedactElem::redacted`
 /// - `LinkElem::dests`
 #[derive(Debug, Clone)]
 pub struct FrameModifiers {
@@ -23,6 +27,8 @@ pub struct FrameModifiers {
     dest: Option<Destination>,
     /// Whether the contents of the frame should be hidden.
     hidden: bool,
+    /// How to fill the contents of the frame if they should be redacted.
+    redacted: Option<Paint>,
 }
 
 impl FrameModifiers {
@@ -31,6 +37,7 @@ impl FrameModifiers {
         Self {
             dest: styles.get_cloned(LinkElem::current),
             hidden: styles.get(HideElem::hidden),
+            redacted: styles.get_cloned(RedactElem::redacted),
         }
     }
 }
@@ -108,6 +115,10 @@ fn modify_frame(
     if modifiers.hidden {
         frame.hide();
     }
+
+    if let Some(fill) = &modifiers.redacted {
+        frame.redact(fill);
+    }
 }
 
 /// Performs layout and modification in one step.
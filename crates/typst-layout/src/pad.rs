@@ -91,3 +91,65 @@ pub fn grow(frame: &mut Frame, inset: &Sides<Rel<Abs>>) {
     frame.set_size(padded);
     frame.translate(offset);
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_library::layout::Ratio;
+
+    use super::*;
+
+    /// A spread of insets with absolute, relative, and mixed components.
+    fn insets() -> Vec<Sides<Rel<Abs>>> {
+        let parts = [
+            Rel::new(Ratio::zero(), Abs::zero()),
+            Rel::new(Ratio::zero(), Abs::pt(5.0)),
+            Rel::new(Ratio::new(0.1), Abs::zero()),
+            Rel::new(Ratio::new(0.25), Abs::pt(-2.0)),
+        ];
+        let mut insets = vec![];
+        for a in parts {
+            for b in parts {
+                insets.push(Sides::new(a, b, b, a));
+            }
+        }
+        insets
+    }
+
+    #[test]
+    fn test_pad_grow_inverts_shrink() {
+        let sizes = [
+            Size::zero(),
+            Size::new(Abs::pt(100.0), Abs::pt(40.0)),
+            Size::new(Abs::pt(595.0), Abs::pt(842.0)),
+        ];
+        for inset in insets() {
+            for size in sizes {
+                let mut frame = Frame::soft(shrink(size, &inset));
+                grow(&mut frame, &inset);
+                assert!(
+                    frame.width().approx_eq(size.x) && frame.height().approx_eq(size.y),
+                    "{size:?} padded by {inset:?} became {:?}",
+                    frame.size(),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pad_shrink_multiple_matches_shrink() {
+        let size = Size::new(Abs::pt(200.0), Abs::pt(300.0));
+        for inset in insets() {
+            let mut shrunk = size;
+            let mut full = size.y;
+            let mut backlog = [Abs::pt(500.0)];
+            let mut last = Some(Abs::pt(700.0));
+            shrink_multiple(&mut shrunk, &mut full, &mut backlog, &mut last, &inset);
+
+            let expected = |y: f64| shrink(Size::new(size.x, Abs::pt(y)), &inset).y;
+            assert_eq!(shrunk, shrink(size, &inset));
+            assert_eq!(full, expected(300.0));
+            assert_eq!(backlog, [expected(500.0)]);
+            assert_eq!(last, Some(expected(700.0)));
+        }
+    }
+}
@@ -15,7 +15,9 @@ use typst_library::introspection::{
     DocumentPosition, Introspector, IntrospectorBuilder, Locator, ManualPageCounter,
     SplitLocator, TagElem,
 };
-use typst_library::layout::{FrameItem, Page, PagedDocument, Point, Position, Transform};
+use typst_library::layout::{
+    FrameItem, Page, PagedDocument, Point, Position, RedactElem, Transform,
+};
 use typst_library::model::DocumentInfo;
 use typst_library::routines::{Arenas, Pair, RealizationKind, Routines};
 use typst_utils::Protected;
@@ -145,6 +147,9 @@ fn layout_pages<'a>(
                 tags.extend(
                     items
                         .iter()
+                        .filter(|(_, styles)| {
+                            styles.get_ref(RedactElem::redacted).is_none()
+                        })
                         .filter_map(|(c, _)| c.to_packed::<TagElem>())
                         .map(|elem| elem.tag.clone()),
                 );
@@ -185,6 +185,7 @@ fn layout_page_run_impl(
         Regions::repeat(area, area.map(Abs::is_finite)),
         styles.get(PageElem::columns),
         styles.get(ColumnsElem::gutter).resolve(styles),
+        styles.get(ColumnsElem::balance),
         FlowMode::Root,
     )?;
 
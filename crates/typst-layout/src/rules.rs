@@ -11,8 +11,9 @@ use typst_library::layout::{
     Abs, AlignElem, Alignment, Axes, BlockBody, BlockElem, ColumnsElem, Em,
     FixedAlignment, GridCell, GridChild, GridElem, GridItem, HAlignment, HElem, HideElem,
     InlineElem, LayoutElem, Length, MoveElem, OuterVAlignment, PadElem, PageElem,
-    PlaceElem, PlacementScope, Region, Rel, RepeatElem, RotateElem, ScaleElem, Sides,
-    Size, Sizing, SkewElem, Spacing, StackChild, StackElem, TrackSizings, VElem,
+    PlaceElem, PlacementScope, RedactElem, Region, Rel, RepeatElem, RotateElem,
+    ScaleElem, Sides, Size, Sizing, SkewElem, Spacing, StackChild, StackElem,
+    TrackSizings, VElem,
 };
 use typst_library::math::EquationElem;
 use typst_library::model::{
@@ -89,6 +90,7 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Paged, SKEW_RULE);
     rules.register(Paged, REPEAT_RULE);
     rules.register(Paged, HIDE_RULE);
+    rules.register(Paged, REDACT_RULE);
     rules.register(Paged, LAYOUT_RULE);
 
     // Visualize.
@@ -750,6 +752,11 @@ const REPEAT_RULE: ShowFn<RepeatElem> = |elem, _, _| {
 const HIDE_RULE: ShowFn<HideElem> =
     |elem, _, _| Ok(elem.body.clone().set(HideElem::hidden, true));
 
+const REDACT_RULE: ShowFn<RedactElem> = |elem, _, styles| {
+    let fill = elem.fill.get_cloned(styles);
+    Ok(elem.body.clone().set(RedactElem::redacted, Some(fill)))
+};
+
 const LAYOUT_RULE: ShowFn<LayoutElem> = |elem, _, _| {
     Ok(BlockElem::multi_layouter(
         elem.clone(),
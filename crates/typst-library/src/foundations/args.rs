@@ -250,9 +250,25 @@ impl Args {
     /// Return an "unexpected argument" error if there is any remaining
     /// argument.
     pub fn finish(self) -> SourceResult<()> {
+        self.finish_with([])
+    }
+
+    /// Return an "unexpected argument" error if there is any remaining
+    /// argument, suggesting one of the given parameter names if an unexpected
+    /// named argument looks like a misspelling of it.
+    pub fn finish_with<'a>(
+        self,
+        params: impl IntoIterator<Item = &'a str>,
+    ) -> SourceResult<()> {
         if let Some(arg) = self.items.first() {
             match &arg.name {
-                Some(name) => bail!(arg.span, "unexpected argument: {name}"),
+                Some(name) => match typst_utils::suggest(name, params) {
+                    Some(param) => bail!(
+                        arg.span, "unexpected argument: {name}";
+                        hint: "did you mean `{param}`?"
+                    ),
+                    None => bail!(arg.span, "unexpected argument: {name}"),
+                },
                 _ => bail!(arg.span, "unexpected argument"),
             }
         }
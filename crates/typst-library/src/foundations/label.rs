@@ -13,6 +13,10 @@ use crate::foundations::{Repr, Str, bail, func, scope, ty};
 /// A labelled element can be [referenced]($ref), [queried]($query) for, and
 /// [styled]($styling) through its label.
 ///
+/// When exporting to PDF, the first element with a given label also becomes a
+/// named destination. This allows other documents and URLs to link directly
+/// to it, e.g. through `document.pdf#label`.
+///
 /// Once constructed, you can get the name of a label using
 /// [`str`]($str/#constructor).
 ///
@@ -54,7 +54,7 @@ impl<'a> Scopes<'a> {
                     None => None,
                 })
             })
-            .ok_or_else(|| unknown_variable(var))
+            .ok_or_else(|| unknown_variable(var, self.names()))
     }
 
     /// Try to access a binding mutably.
@@ -66,7 +66,7 @@ impl<'a> Scopes<'a> {
                 match self.base.and_then(|base| base.global.scope().get(var)) {
                     Some(_) => cannot_mutate_constant(var),
                     _ if var == "std" => cannot_mutate_constant(var),
-                    _ => unknown_variable(var),
+                    _ => unknown_variable(var, []),
                 }
             })
     }
@@ -91,6 +91,15 @@ impl<'a> Scopes<'a> {
             })
     }
 
+    /// The names of all bindings visible from the active scope, innermost
+    /// first.
+    fn names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(&self.top)
+            .chain(self.scopes.iter().rev())
+            .chain(self.base.map(|base| base.global.scope()))
+            .flat_map(|scope| scope.iter().map(|(name, _)| name.as_str()))
+    }
+
     /// Check if an std variable is shadowed.
     pub fn check_std_shadowed(&self, var: &str) -> bool {
         self.base.is_some_and(|base| base.global.scope().get(var).is_some())
@@ -411,7 +420,10 @@ fn cannot_mutate_constant(var: &str) -> HintedString {
 
 /// The error message when a variable wasn't found.
 #[cold]
-fn unknown_variable(var: &str) -> HintedString {
+fn unknown_variable<'a>(
+    var: &str,
+    names: impl IntoIterator<Item = &'a str>,
+) -> HintedString {
     let mut res = HintedString::new(eco_format!("unknown variable: {}", var));
 
     if var.contains('-') {
@@ -421,6 +433,8 @@ fn unknown_variable(var: &str) -> HintedString {
             if var.matches('-').count() > 1 { "s" } else { "" },
             var.replace('-', " - ")
         ));
+    } else if let Some(name) = typst_utils::suggest(var, names) {
+        res.hint(eco_format!("did you mean `{name}`?"));
     }
 
     res
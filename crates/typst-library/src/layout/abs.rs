@@ -281,4 +281,21 @@ mod tests {
     fn test_length_unit_conversion() {
         assert!((Abs::mm(150.0).to_cm() - 15.0) < 1e-4);
     }
+
+    #[test]
+    fn test_abs_algebra() {
+        let values = [-12.5, -1.0, 0.0, 0.25, 3.0, 595.0].map(Abs::pt);
+        for a in values {
+            assert_eq!(a + Abs::zero(), a);
+            assert_eq!(-(-a), a);
+            assert!((a - a).approx_empty());
+            assert!(a.fits(a));
+            for b in values {
+                assert_eq!(a + b, b + a);
+                assert!(((a + b) - b).approx_eq(a));
+                assert_eq!(a.min(b) + a.max(b), a + b);
+                assert_eq!(a.fits(b), b <= a);
+            }
+        }
+    }
 }
@@ -738,3 +738,41 @@ impl From<Side> for FixedAlignment {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alignment_position() {
+        let extents = [0.0, 0.25, 3.0, 595.0].map(Abs::pt);
+        let fixed = [FixedAlignment::Start, FixedAlignment::Center, FixedAlignment::End];
+        for align in fixed {
+            assert_eq!(align.inv().inv(), align);
+            for extent in extents {
+                let pos = align.position(extent);
+                assert!(pos >= Abs::zero() && extent.fits(pos));
+                assert!((pos + align.inv().position(extent)).approx_eq(extent));
+            }
+        }
+    }
+
+    #[test]
+    fn test_alignment_fix_commutes_with_inv() {
+        let horizontal = [
+            HAlignment::Start,
+            HAlignment::Left,
+            HAlignment::Center,
+            HAlignment::Right,
+            HAlignment::End,
+        ];
+        for dir in [Dir::LTR, Dir::RTL, Dir::TTB, Dir::BTT] {
+            for align in horizontal {
+                assert_eq!(align.inv().fix(dir), align.fix(dir).inv());
+            }
+            for align in [VAlignment::Top, VAlignment::Horizon, VAlignment::Bottom] {
+                assert_eq!(align.inv().fix(dir), align.fix(dir).inv());
+            }
+        }
+    }
+}
@@ -6,10 +6,9 @@ use crate::layout::{Length, Ratio, Rel};
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function lets you separate the interior of any container into
-/// multiple columns. It will currently not balance the height of the columns.
-/// Instead, the columns will take up the height of their container or the
-/// remaining height on the page. Support for balanced columns is planned for
-/// the future.
+/// multiple columns. By default, the columns will take up the height of their
+/// container or the remaining height on the page. If you'd rather have the
+/// columns end at roughly the same height, enable [balancing]($columns.balance).
 ///
 /// When arranging content across multiple columns, use [`colbreak`]($colbreak)
 /// to explicitly continue in the next column.
@@ -66,6 +65,25 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the heights of the columns in the final region.
+    ///
+    /// When enabled, the columns in the last region the content occupies are
+    /// shortened so that they end at roughly the same height, instead of
+    /// filling up each column before moving on to the next one.
+    ///
+    /// A set rule for this property also applies to the columns of a
+    /// [page]($page.columns). There, the last page before a page break or the
+    /// end of the document is balanced.
+    ///
+    /// ```example
+    /// #set page(height: auto)
+    /// #columns(2, balance: true)[
+    ///   #lorem(30)
+    /// ]
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
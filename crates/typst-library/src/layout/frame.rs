@@ -9,7 +9,7 @@ use typst_utils::{LazyHash, Numeric};
 
 use crate::foundations::{Dict, Label, Value, cast, dict};
 use crate::introspection::{Location, Tag};
-use crate::layout::{Abs, Axes, FixedAlignment, Length, Point, Size, Transform};
+use crate::layout::{Abs, Axes, FixedAlignment, Length, Point, Rect, Size, Transform};
 use crate::model::Destination;
 use crate::text::TextItem;
 use crate::visualize::{Color, Curve, FixedStroke, Geometry, Image, Paint, Shape};
@@ -313,6 +313,39 @@ impl Frame {
         });
     }
 
+    /// Replace all content in the frame with filled rectangles covering the
+    /// same area and remove all metadata.
+    pub fn redact(&mut self, fill: &Paint) {
+        Arc::make_mut(&mut self.items).retain_mut(|(pos, item)| {
+            let rect = match item {
+                FrameItem::Group(group) => {
+                    // All tags are removed, so the group must not refer to a
+                    // logical parent or carry a label anymore.
+                    group.label = None;
+                    group.parent = None;
+                    group.frame.redact(fill);
+                    return !group.frame.is_empty();
+                }
+                FrameItem::Text(text) => {
+                    let metrics = text.font.metrics();
+                    let top = metrics.ascender.at(text.size);
+                    let bottom = metrics.descender.at(text.size);
+                    Rect::new(Point::with_y(-top), Point::new(text.width(), -bottom))
+                }
+                FrameItem::Shape(shape, _) => shape.geometry.bbox(),
+                FrameItem::Image(_, size, _) => Rect::from_pos_size(Point::zero(), *size),
+                FrameItem::Link(..) | FrameItem::Tag(_) => return false,
+            };
+
+            *pos += rect.min;
+            *item = FrameItem::Shape(
+                Geometry::Rect(rect.size()).filled(fill.clone()),
+                Span::detached(),
+            );
+            true
+        });
+    }
+
     /// Add a background fill.
     pub fn fill(&mut self, fill: impl Into<Paint>) {
         self.prepend(
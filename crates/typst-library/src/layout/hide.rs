@@ -1,5 +1,6 @@
 use crate::foundations::{Content, elem};
 use crate::introspection::Tagged;
+use crate::visualize::{Color, Paint};
 
 /// Hides content without affecting layout.
 ///
@@ -21,7 +22,9 @@ use crate::introspection::Tagged;
 ///
 /// Note that, depending on the circumstances, it may be possible for content to
 /// be reverse engineered based on its size in the layout. We thus do not
-/// recommend using this function to hide highly sensitive information.
+/// recommend using this function to hide highly sensitive information. If you
+/// need to remove content without any traces besides its size, use the
+/// [`redact`] function instead.
 #[elem(Tagged)]
 pub struct HideElem {
     /// The content to hide.
@@ -33,3 +36,44 @@ pub struct HideElem {
     #[ghost]
     pub hidden: bool,
 }
+
+/// Replaces content with filled areas of the same size.
+///
+/// Like with [`hide`], the layout still "sees" the content, so the surrounding
+/// text flows exactly as it would without redaction. Redacted text can still
+/// break across lines and redacted blocks across pages. But nothing of the
+/// content makes it into the output: Text, shapes, and images are replaced by
+/// filled rectangles covering the same area, and links as well as elements
+/// within the content are removed. This means that, unlike with `hide`, a
+/// redacted heading will not show up in the PDF's Document Outline and a
+/// redacted figure cannot be referenced or found by a [query].
+///
+/// # Example
+/// ```example
+/// The agent's name is
+/// #redact[James Bond].
+/// ```
+///
+/// Like with `hide`, the size of the redacted area is still the size of the
+/// original content, so it may be possible to guess short redacted words from
+/// their width.
+#[elem]
+pub struct RedactElem {
+    /// How to fill the area of the redacted content.
+    ///
+    /// ```example
+    /// #set redact(fill: gray)
+    /// Classified: #redact[Top secret]
+    /// ```
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+
+    /// The content to redact.
+    #[required]
+    pub body: Content,
+
+    /// This style is set on the content contained in the `redact` element.
+    #[internal]
+    #[ghost]
+    pub redacted: Option<Paint>,
+}
@@ -97,6 +97,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<RotateElem>();
     global.define_elem::<SkewElem>();
     global.define_elem::<HideElem>();
+    global.define_elem::<RedactElem>();
     global.define_func::<measure>();
     global.define_func::<layout>();
     global.reset_category();
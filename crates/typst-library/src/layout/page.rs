@@ -176,7 +176,8 @@ pub struct PageElem {
     /// How many columns the page has.
     ///
     /// If you need to insert columns into a page or other container, you can
-    /// also use the [`columns` function]($columns).
+    /// also use the [`columns` function]($columns). Its set rules also
+    /// configure the gutter and [balancing]($columns.balance) of page columns.
     ///
     /// ```example:single
     /// #set page(columns: 2, height: 4.8cm)
@@ -535,6 +536,33 @@ pub struct PagedDocument {
     pub introspector: Introspector,
 }
 
+impl PagedDocument {
+    /// Determines which pages changed compared to an earlier version of the
+    /// document.
+    ///
+    /// Returns the indices of all pages that differ from the page at the same
+    /// index in `previous`, including pages that did not exist before. If the
+    /// document got shorter, the trailing pages of `previous` are not reported
+    /// and should be dropped by the caller.
+    ///
+    /// Pages are compared by hash, which is cheap after an incremental
+    /// compilation since unchanged frames are shared and their hashes are
+    /// cached. This allows preview applications to only re-render changed
+    /// pages.
+    pub fn changed_pages(&self, previous: &PagedDocument) -> Vec<usize> {
+        self.pages
+            .iter()
+            .enumerate()
+            .filter(|&(i, page)| {
+                previous.pages.get(i).is_none_or(|prev| {
+                    typst_utils::hash128(prev) != typst_utils::hash128(page)
+                })
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
+
 /// A finished page.
 #[derive(Debug, Clone, Hash)]
 pub struct Page {
@@ -992,10 +1020,38 @@ papers! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::layout::Size;
 
     #[test]
     fn test_paged_document_is_send_and_sync() {
         fn ensure_send_and_sync<T: Send + Sync>() {}
         ensure_send_and_sync::<PagedDocument>();
     }
+
+    #[test]
+    fn test_paged_document_changed_pages() {
+        fn page(width: f64) -> Page {
+            Page {
+                frame: Frame::soft(Size::new(Abs::pt(width), Abs::pt(100.0))),
+                fill: Smart::Auto,
+                numbering: None,
+                supplement: Content::empty(),
+                number: 1,
+            }
+        }
+
+        fn document(widths: &[f64]) -> PagedDocument {
+            PagedDocument {
+                pages: widths.iter().copied().map(page).collect(),
+                ..Default::default()
+            }
+        }
+
+        let previous = document(&[10.0, 20.0, 30.0]);
+        assert_eq!(previous.changed_pages(&previous), Vec::<usize>::new());
+        assert_eq!(document(&[10.0, 25.0, 30.0]).changed_pages(&previous), [1]);
+        assert_eq!(document(&[10.0, 20.0, 30.0, 40.0]).changed_pages(&previous), [3]);
+        assert_eq!(document(&[10.0]).changed_pages(&previous), Vec::<usize>::new());
+        assert_eq!(previous.changed_pages(&PagedDocument::default()), [0, 1, 2]);
+    }
 }
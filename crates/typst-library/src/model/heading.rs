@@ -1,14 +1,18 @@
 use std::num::NonZeroUsize;
 
-use ecow::EcoString;
-use typst_utils::NonZeroExt;
+use ecow::{EcoString, eco_format};
+use rustc_hash::{FxHashMap, FxHashSet};
+use typst_utils::{NonZeroExt, PicoStr};
 
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{
-    Content, NativeElement, Packed, ShowSet, Smart, StyleChain, Styles, Synthesize, elem,
+    Content, Label, NativeElement, Packed, ShowSet, Smart, StyleChain, Styles,
+    Synthesize, elem,
+};
+use crate::introspection::{
+    Count, Counter, CounterUpdate, Introspector, Locatable, Location, Tagged,
 };
-use crate::introspection::{Count, Counter, CounterUpdate, Locatable, Tagged};
 use crate::layout::{BlockElem, Em, Length};
 use crate::model::{Numbering, Outlinable, Refable, Supplement};
 use crate::text::{FontWeight, LocalName, TextElem, TextSize};
@@ -345,3 +349,77 @@ impl Outlinable for Packed<HeadingElem> {
 impl LocalName for Packed<HeadingElem> {
     const KEY: &'static str = "heading";
 }
+
+/// Derives identifiers for all unlabelled headings in the document.
+///
+/// The identifiers are slugs of the headings' plain text, e.g.
+/// `getting-started` for a heading reading "Getting Started". Headings with the
+/// same text are disambiguated in document order with the suffixes `-2`, `-3`,
+/// and so on.
+///
+/// Since the identifier only depends on the heading's text and on headings with
+/// the same text, it stays the same when unrelated content changes. This makes
+/// it suitable for fragments of external links. Slugs starting with a digit
+/// are prefixed with `h-` (e.g. `h-2024` for "2024"), since such identifiers
+/// can't be used as CSS selectors without escaping. Headings whose text yields
+/// no slug at all are left out.
+///
+/// Slugs that collide with a label or with one of the `reserved` identifiers
+/// (e.g. explicit `id` attributes in HTML export) are disambiguated in the same
+/// way as duplicate slugs.
+pub fn heading_ids(
+    introspector: &Introspector,
+    reserved: &FxHashSet<EcoString>,
+) -> FxHashMap<Location, EcoString> {
+    let mut ids = FxHashMap::default();
+    let mut used = reserved.clone();
+
+    for elem in introspector.query(&HeadingElem::ELEM.select()).iter() {
+        let Some(loc) = elem.location() else { continue };
+        if elem.label().is_some() {
+            continue;
+        }
+
+        let heading = elem.to_packed::<HeadingElem>().unwrap();
+        let mut slug = slugify(&heading.body.plain_text());
+        if slug.is_empty() {
+            continue;
+        } else if slug.starts_with(|c: char| c.is_numeric()) {
+            slug = eco_format!("h-{slug}");
+        }
+
+        let is_taken = |id: &str| {
+            used.contains(id)
+                || PicoStr::get(id)
+                    .and_then(Label::new)
+                    .is_some_and(|label| introspector.label_count(label) > 0)
+        };
+
+        let mut id = slug.clone();
+        let mut counter = 1;
+        while is_taken(&id) {
+            counter += 1;
+            id = eco_format!("{slug}-{counter}");
+        }
+
+        used.insert(id.clone());
+        ids.insert(loc, id);
+    }
+
+    ids
+}
+
+/// Turns text into a lowercase identifier consisting of alphanumeric runs
+/// joined by hyphens.
+fn slugify(text: &str) -> EcoString {
+    let mut slug = EcoString::new();
+    for word in text.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()) {
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        for c in word.chars().flat_map(char::to_lowercase) {
+            slug.push(c);
+        }
+    }
+    slug
+}
@@ -94,6 +94,16 @@ use crate::text::{LocalName, TextElem};
 ///   integer will be added. For instance, if the label `<mylabel>` exists
 ///   twice, it would turn into `mylabel-1` and `mylabel-2`.
 ///
+/// - If the target is a heading without a label, an ID is derived from its
+///   text. For instance, a heading reading "Getting Started" would receive the
+///   ID `getting-started`. Headings with the same text are disambiguated with
+///   a suffix, as in `getting-started-2`, as are IDs that are already taken
+///   by a label or an explicitly set `id` attribute. IDs that would start with
+///   a digit are prefixed with `h-`. Such headings receive their ID even
+///   if nothing in the document links to them. The same IDs are used as named
+///   destinations in PDF export, so that they remain stable across unrelated
+///   changes to the document and can be used to link into it.
+///
 /// - Otherwise, a unique ID of the form `loc-` followed by an integer will be
 ///   generated.
 #[elem(Locatable)]
@@ -134,6 +134,14 @@ impl Font {
         find_name(&self.0.ttf, id)
     }
 
+    /// Whether the font's license permits embedding it into documents.
+    ///
+    /// This is not the case if the font specifies "Restricted License
+    /// embedding" in its `OS/2` table.
+    pub fn is_embeddable(&self) -> bool {
+        self.0.ttf.permissions() != Some(ttf_parser::Permissions::Restricted)
+    }
+
     /// A reference to the underlying `ttf-parser` face.
     pub fn ttf(&self) -> &ttf_parser::Face<'_> {
         // We can't implement Deref because that would leak the
@@ -359,7 +359,14 @@ fn create_wrapper_closure(func: &Func) -> TokenStream {
     };
 
     // Throws errors about unexpected arguments.
-    let finish = (!func.special.args).then(|| quote! { args.take().finish()?; });
+    let finish = (!func.special.args).then(|| {
+        let named = func
+            .params
+            .iter()
+            .filter(|param| param.named && !param.external)
+            .map(|param| &param.name);
+        quote! { args.take().finish_with([#(#named),*])?; }
+    });
 
     // This is the actual function call.
     let call = {
@@ -16,6 +16,14 @@ pub(crate) fn attach_files(
     let elements = gc.document.introspector.query(&AttachElem::ELEM.select());
 
     for elem in &elements {
+        // Only attach files from exported pages.
+        let page = gc.document.introspector.page(elem.location().unwrap());
+        if let Some(ranges) = &gc.options.page_ranges
+            && !ranges.includes_page(page)
+        {
+            continue;
+        }
+
         let elem = elem.to_packed::<AttachElem>().unwrap();
         let span = elem.span();
         let derived_path = &elem.path.derived;
@@ -15,12 +15,12 @@ use smallvec::SmallVec;
 use typst_library::diag::{
     At, ExpectInternal, SourceDiagnostic, SourceResult, bail, error,
 };
-use typst_library::foundations::{NativeElement, Repr};
+use typst_library::foundations::Repr;
 use typst_library::introspection::{Location, Tag};
 use typst_library::layout::{
     Frame, FrameItem, GroupItem, PagedDocument, Size, Transform,
 };
-use typst_library::model::HeadingElem;
+use typst_library::model::heading_ids;
 use typst_library::text::Font;
 use typst_library::visualize::{Geometry, Paint};
 use typst_syntax::Span;
@@ -41,6 +41,7 @@ use crate::util::{AbsExt, TransformExt, convert_path, display_font};
 pub fn convert(
     typst_document: &PagedDocument,
     options: &PdfOptions,
+    warnings: &mut EcoVec<SourceDiagnostic>,
 ) -> SourceResult<Vec<u8>> {
     let settings = SerializeSettings {
         compress_content_streams: true,
@@ -69,6 +70,7 @@ pub fn convert(
 
     convert_pages(&mut gc, &mut document)?;
     attach_files(&gc, &mut document)?;
+    check_font_licenses(&gc, warnings)?;
     let (doc_lang, tree) = tags::resolve(&mut gc)?;
 
     document.set_outline(build_outline(&gc));
@@ -78,6 +80,57 @@ pub fn convert(
     finish(document, gc, options.standards.config)
 }
 
+/// Reports fonts whose license does not permit embedding them.
+///
+/// This is a warning unless [`PdfOptions::deny_restricted_fonts`] is set.
+/// Standards that prohibit such fonts already report them during validation,
+/// so nothing is reported here in that case.
+fn check_font_licenses(
+    gc: &GlobalContext,
+    warnings: &mut EcoVec<SourceDiagnostic>,
+) -> SourceResult<()> {
+    // Standards that reject such fonts already fail export for them, so
+    // there's no need to warn about them, too.
+    let validator = gc.options.standards.config.validator();
+    let mut fonts: Vec<&Font> = gc
+        .fonts_forward
+        .iter()
+        .filter(|(font, _)| !font.is_embeddable())
+        .filter(|(_, font)| {
+            !validator.prohibits(&ValidationError::RestrictedLicense((*font).clone()))
+        })
+        .map(|(font, _)| font)
+        .collect();
+    fonts.sort_by(|a, b| a.info().family.cmp(&b.info().family));
+
+    let deny = gc.options.deny_restricted_fonts;
+    let diags: EcoVec<_> = fonts
+        .into_iter()
+        .map(|font| {
+            let message = eco_format!(
+                "license of {} does not permit embedding",
+                display_font(Some(font))
+            );
+            let diag = if deny {
+                SourceDiagnostic::error(Span::detached(), message)
+            } else {
+                SourceDiagnostic::warning(Span::detached(), message)
+            };
+            diag.with_hint(
+                "the font has specified \"Restricted License embedding\" in its metadata",
+            )
+            .with_hint("embedding the font may violate its license")
+        })
+        .collect();
+
+    if deny && !diags.is_empty() {
+        return Err(diags);
+    }
+
+    warnings.extend(diags);
+    Ok(())
+}
+
 fn convert_pages(gc: &mut GlobalContext, document: &mut Document) -> SourceResult<()> {
     for (i, typst_page) in gc.document.pages.iter().enumerate() {
         if gc.page_index_converter.pdf_page_index(i).is_none() {
@@ -692,14 +745,13 @@ fn collect_named_destinations(
 ) -> FxHashMap<Location, NamedDestination> {
     let mut locs_to_names = FxHashMap::default();
 
-    // Find all headings that have a label and are the first among other
-    // headings with the same label.
+    // Find all elements that have a label and are the first among other
+    // elements with the same label.
     let matches: Vec<_> = {
         let mut seen = FxHashSet::default();
         document
             .introspector
-            .query(&HeadingElem::ELEM.select())
-            .iter()
+            .all()
             .filter_map(|elem| elem.location().zip(elem.label()))
             .filter(|&(_, label)| seen.insert(label))
             .collect()
@@ -714,6 +766,15 @@ fn collect_named_destinations(
         }
     }
 
+    // Give unlabelled headings a named destination derived from their text,
+    // so that links into the document survive changes to unrelated content.
+    for (loc, id) in heading_ids(&document.introspector, &FxHashSet::default()) {
+        let pos = document.introspector.position(loc);
+        if let Some(dest) = crate::link::pos_to_xyz(pic, pos) {
+            locs_to_names.insert(loc, NamedDestination::new(id.to_string(), dest));
+        }
+    }
+
     locs_to_names
 }
 
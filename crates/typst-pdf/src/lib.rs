@@ -17,19 +17,34 @@ pub use self::metadata::{Timestamp, Timezone};
 
 use std::fmt::{self, Debug, Formatter};
 
-use ecow::eco_format;
+use ecow::{EcoVec, eco_format};
 use krilla::configure::Validator;
 use serde::{Deserialize, Serialize};
-use typst_library::diag::{SourceResult, StrResult, bail};
+use typst_library::diag::{SourceResult, StrResult, Warned, bail};
 use typst_library::foundations::Smart;
 use typst_library::layout::{PageRanges, PagedDocument};
 
 /// Export a document into a PDF file.
 ///
-/// Returns the raw bytes making up the PDF file.
-#[typst_macros::time(name = "pdf")]
+/// Returns the raw bytes making up the PDF file. Use [`pdf_with_warnings`] to
+/// also receive the warnings generated during export.
 pub fn pdf(document: &PagedDocument, options: &PdfOptions) -> SourceResult<Vec<u8>> {
-    convert::convert(document, options)
+    pdf_with_warnings(document, options).output
+}
+
+/// Export a document into a PDF file, collecting warnings.
+///
+/// Returns the raw bytes making up the PDF file, alongside warnings generated
+/// during export, for example about fonts whose license does not permit
+/// embedding them.
+#[typst_macros::time(name = "pdf")]
+pub fn pdf_with_warnings(
+    document: &PagedDocument,
+    options: &PdfOptions,
+) -> Warned<SourceResult<Vec<u8>>> {
+    let mut warnings = EcoVec::new();
+    let output = convert::convert(document, options, &mut warnings);
+    Warned { output, warnings }
 }
 
 /// Settings for PDF export.
@@ -60,6 +75,10 @@ pub struct PdfOptions<'a> {
     /// circumstances, for example when trying to reduce the size of a document,
     /// it can be desirable to disable tagged PDF.
     pub tagged: bool,
+    /// Whether to fail export instead of emitting a warning when the license
+    /// of a used font does not permit embedding it. Standards that prohibit
+    /// such fonts, like PDF/A, always fail export.
+    pub deny_restricted_fonts: bool,
 }
 
 impl PdfOptions<'_> {
@@ -78,6 +97,7 @@ impl Default for PdfOptions<'_> {
             page_ranges: None,
             standards: PdfStandards::default(),
             tagged: true,
+            deny_restricted_fonts: false,
         }
     }
 }
@@ -18,8 +18,9 @@ pub(crate) fn build_outline(gc: &GlobalContext) -> KrillaOutline {
                 .get(StyleChain::default())
                 .unwrap_or_else(|| heading.outlined.get(StyleChain::default()));
 
+            // Only headings on exported pages get a bookmark.
             let visible = gc.options.page_ranges.as_ref().is_none_or(|ranges| {
-                !ranges.includes_page(
+                ranges.includes_page(
                     gc.document.introspector.page(elem.location().unwrap()),
                 )
             });
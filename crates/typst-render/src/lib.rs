@@ -16,6 +16,12 @@ use typst_library::visualize::{Color, Geometry, Paint};
 ///
 /// This renders the page at the given number of pixels per point and returns
 /// the resulting `tiny-skia` pixel buffer.
+///
+/// To render a page at a fixed pixel width, for example as a thumbnail, use
+/// `width / page.frame.width().to_f32()` as the number of pixels per point.
+/// Pages of zero width need to be handled separately, as this does not yield
+/// a finite scale for them. The dimensions of the resulting pixmap are rounded
+/// to whole pixels.
 #[typst_macros::time(name = "render")]
 pub fn render(page: &Page, pixel_per_pt: f32) -> sk::Pixmap {
     let size = page.frame.size();
@@ -13,6 +13,7 @@ mod pico;
 mod protected;
 mod round;
 mod scalar;
+mod suggest;
 #[path = "version.rs"]
 mod version_;
 
@@ -25,6 +26,7 @@ pub use self::pico::{PicoStr, ResolvedPicoStr};
 pub use self::protected::Protected;
 pub use self::round::{round_int_with_precision, round_with_precision};
 pub use self::scalar::Scalar;
+pub use self::suggest::suggest;
 pub use self::version_::{TypstVersion, display_commit, version};
 
 #[doc(hidden)]
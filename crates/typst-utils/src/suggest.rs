@@ -0,0 +1,67 @@
+/// Find the candidate that is most similar to `name`, if any is similar
+/// enough to likely be a misspelling of it.
+///
+/// Similarity is measured by edit distance, which may be at most a third of
+/// the name's length. Exact matches are not considered. If multiple
+/// candidates are equally similar, the first one is returned.
+pub fn suggest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let limit = name.chars().count() / 3;
+    if limit == 0 {
+        return None;
+    }
+
+    candidates
+        .into_iter()
+        .filter_map(|candidate| {
+            let distance = edit_distance(name, candidate);
+            (distance > 0 && distance <= limit).then_some((distance, candidate))
+        })
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("abc", ""), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("pagebreak", "pagebrake"), 2);
+        assert_eq!(edit_distance("größe", "grösse"), 2);
+    }
+
+    #[test]
+    fn test_suggest() {
+        let names = ["pagebreak", "parbreak", "page", "par"];
+        assert_eq!(suggest("pagebrek", names), Some("pagebreak"));
+        assert_eq!(suggest("parbreack", names), Some("parbreak"));
+        assert_eq!(suggest("pag", names), Some("page"));
+        assert_eq!(suggest("pa", names), None);
+        assert_eq!(suggest("page", names), None);
+        assert_eq!(suggest("columns", names), None);
+    }
+}
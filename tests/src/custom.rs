@@ -1,10 +1,17 @@
+use std::collections::HashMap;
 use std::fmt::Write;
+use std::sync::Arc;
 
+use ecow::eco_format;
+use hayro_syntax::object::{Array, Dict, Object};
 use typst::World;
-use typst::foundations::Smart;
+use typst::diag::{StrResult, bail};
+use typst::foundations::{Label, Selector, Smart};
 use typst::introspection::{Location, Tag};
 use typst::layout::{Frame, FrameItem, PagedDocument};
 use typst::model::DocumentInfo;
+use typst_pdf::PdfOptions;
+use typst_utils::PicoStr;
 
 use crate::collect::Test;
 use crate::world::TestWorld;
@@ -46,6 +53,27 @@ pub fn check(test: &Test, world: &TestWorld, doc: Option<&PagedDocument>) -> Str
                 sink.push_str("missing document");
             }
         }
+        "redact-text" => {
+            let secrets = ["James", "Bond", "MI6", "Secret", "Intelligence", "Service"];
+            check_redacted(&mut sink, doc, &secrets);
+        }
+        "redact-block" => {
+            check_redacted(&mut sink, doc, &["Lorem", "ipsum"]);
+            if doc.is_some_and(|doc| doc.pages.len() < 2) {
+                sink.push_str("redacted block did not break across pages");
+            }
+        }
+        "redact-no-traces" => check_redacted(&mut sink, doc, &["Secret"]),
+        "pdf-named-destinations" => {
+            if let Some(doc) = doc {
+                if let Err(message) = check_named_destinations(doc, &["fig", "eq", "dup"])
+                {
+                    sink.push_str(&message);
+                }
+            } else {
+                sink.push_str("missing document");
+            }
+        }
         _ => {}
     }
     sink
@@ -92,3 +120,76 @@ fn check_balanced(doc: &PagedDocument) -> Result<(), &'static str> {
     let mut stack = Vec::new();
     doc.pages.iter().try_for_each(|page| visit(&mut stack, &page.frame))
 }
+
+/// Check that none of the given words and no links remain in the document.
+fn check_redacted(sink: &mut String, doc: Option<&PagedDocument>, secrets: &[&str]) {
+    fn visit(sink: &mut String, frame: &Frame, secrets: &[&str]) {
+        for (_, item) in frame.items() {
+            match item {
+                FrameItem::Group(group) => visit(sink, &group.frame, secrets),
+                FrameItem::Text(text) => {
+                    for secret in secrets.iter().filter(|s| text.text.contains(**s)) {
+                        writeln!(sink, "redacted text {secret:?} is in the output")
+                            .unwrap();
+                    }
+                }
+                FrameItem::Link(..) => sink.push_str("redacted link is in the output\n"),
+                _ => {}
+            }
+        }
+    }
+
+    let Some(doc) = doc else {
+        sink.push_str("missing document");
+        return;
+    };
+
+    for page in &doc.pages {
+        visit(sink, &page.frame, secrets);
+    }
+}
+
+/// Check that the exported PDF has a named destination for each of the given
+/// labels and that it points to the first element with that label.
+fn check_named_destinations(doc: &PagedDocument, labels: &[&str]) -> StrResult<()> {
+    let pdf = typst_pdf::pdf(doc, &PdfOptions::default())
+        .map_err(|_| "failed to export PDF")?;
+    let pdf = hayro_syntax::Pdf::new(Arc::new(pdf))
+        .map_err(|e| eco_format!("couldn't load PDF: {e:?}"))?;
+    let catalog_ref = pdf.xref().root_id();
+    let catalog = pdf.xref().get::<Dict>(catalog_ref).ok_or("missing catalog")?;
+    let entries = catalog
+        .get::<Dict>(b"Names".as_slice())
+        .and_then(|names| names.get::<Dict>(b"Dests".as_slice()))
+        .and_then(|dests| dests.get::<Array>(b"Names".as_slice()))
+        .ok_or("missing named destinations")?;
+
+    // The entries alternate between names and `[page /XYZ left top zoom]`
+    // destinations.
+    let mut lefts = HashMap::new();
+    let mut iter = entries.iter::<Object>();
+    while let (Some(Object::String(name)), Some(Object::Array(dest))) =
+        (iter.next(), iter.next())
+    {
+        let name = String::from_utf8_lossy(&name.get()).into_owned();
+        let Some(Object::Number(left)) = dest.iter::<Object>().nth(2) else {
+            bail!("{name}: expected an XYZ destination");
+        };
+        lefts.insert(name, left.as_f64());
+    }
+
+    for &name in labels {
+        let Some(&left) = lefts.get(name) else {
+            bail!("missing named destination for <{name}>");
+        };
+        let label = Label::new(PicoStr::intern(name)).unwrap();
+        let first = doc.introspector.query(&Selector::Label(label));
+        let loc = first.first().and_then(|elem| elem.location()).unwrap();
+        let pos = doc.introspector.position(loc).as_paged_or_default();
+        if (pos.point.x.to_pt() - left).abs() > 0.01 {
+            bail!("named destination for <{name}> does not point to its first element");
+        }
+    }
+
+    Ok(())
+}
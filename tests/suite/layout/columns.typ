@@ -111,6 +111,44 @@ This is a normal page. Very normal.
 #colbreak()
 In flow.
 
+--- columns-balance paged ---
+// Balanced columns end at roughly the same height.
+#context {
+  let body = lorem(40)
+  let unbalanced = measure(width: 200pt, columns(2, body))
+  let balanced = measure(width: 200pt, columns(2, balance: true, body))
+  test(balanced.width, unbalanced.width)
+  assert(balanced.height < unbalanced.height * 0.6)
+}
+
+--- columns-balance-final-region pdf ---
+// Only the columns in the final region after the page break are balanced. The
+// bottom float stays at the bottom of that region instead of moving up to the
+// balanced height.
+#set page(width: 160pt, height: 120pt, margin: 10pt)
+#columns(2, balance: true)[
+  #lorem(40)
+  #place(bottom, scope: "parent", float: true)[#metadata(none) <bottom> Bottom]
+]
+#context {
+  let pos = locate(<bottom>).position()
+  assert(pos.page > 1)
+  assert(pos.y > 80pt)
+}
+
+--- columns-balance-page pdf ---
+// Page columns are balanced on the last page.
+#set page(width: 160pt, height: 120pt, margin: 10pt, columns: 2)
+#set columns(balance: true)
+#lorem(40)
+#metadata(none) <end>
+#context {
+  let pos = locate(<end>).position()
+  assert(pos.page > 1)
+  assert(pos.x > 80pt)
+  assert(pos.y < 80pt)
+}
+
 --- issue-columns-heading paged ---
 // The well-known columns bug.
 #set page(height: 70pt)
@@ -102,3 +102,32 @@ A pirate. @arrgh \
     [= D],
   ))
 )))
+
+--- redact-size paged ---
+// Redacted content takes up the same space as the original.
+#context test(measure(redact[Hello World]).width, measure[Hello World].width)
+
+--- redact-text pdf ---
+// Redacted text keeps the surrounding layout and breaks across lines. Neither
+// its text nor its link make it into the output. Checked in `custom.rs`.
+#set page(width: 120pt)
+The agent's name is #redact[James Bond]. He works for
+#redact[MI6, the #link("https://www.sis.gov.uk")[Secret Intelligence Service]]
+of the United Kingdom.
+
+--- redact-block pdf ---
+// Redacted blocks break across pages. Checked in `custom.rs`.
+#set page(width: 120pt, height: 100pt)
+#set redact(fill: gray)
+#redact[
+  #lorem(20)
+  #rect(fill: red, width: 100%)
+  #lorem(20)
+]
+
+--- redact-no-traces pdf ---
+// Elements in redacted content are invisible to introspection and leave no
+// text behind. Checked in `custom.rs`.
+#redact[= Secret <secret>]
+#context test(query(heading).len(), 0)
+#context test(query(<secret>).len(), 0)
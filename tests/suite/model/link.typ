@@ -118,6 +118,40 @@ See #metadata(none) <t8>
   list.item(link(it.location(), it.body))
 }
 
+--- link-html-heading-slug html ---
+// Tests stable ID generation for unlabelled headings.
+
+= Getting Started  // => getting-started
+= Getting started! // => getting-started-2
+= Über uns         // => über-uns
+= Intro            // => intro-2 (intro exists)
+= Loc 1            // => loc-1
+= 2024             // => h-2024 (starts with a digit)
+= ?!               // no slug => loc-2 (loc-1 exists)
+
+#metadata(none) <intro>
+
+#context for it in query(heading) {
+  list.item(link(it.location(), it.body))
+}
+
+--- link-html-heading-slug-explicit-id html ---
+// Generated IDs don't collide with explicitly set IDs.
+#html.div(id: "intro")[Text]
+#html.div(id: "loc-1")[Text]
+
+= Intro // => intro-2
+= ?!    // no slug => loc-2
+
+#context for it in query(heading) {
+  list.item(link(it.location(), it.body))
+}
+
+--- link-html-heading-slug-unlinked html ---
+// Unlabelled headings receive an ID even if nothing links to them.
+= Not linked to
+Some text.
+
 --- link-html-id-existing html ---
 // Test that linking reuses the existing ID, if any.
 #html.div[
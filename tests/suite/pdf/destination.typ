@@ -0,0 +1,12 @@
+// Test named destinations. The checks for these tests are in `custom.rs`, as
+// we have no PDF testing infrastructure for them.
+
+--- pdf-named-destinations paged ---
+// Labelled figures and equations get a named destination. Of the elements
+// sharing a label, only the first one does.
+#place(left, hide[
+  #figure[A] <fig>
+  $ x $ <eq>
+  #box[First] <dup>
+])
+#place(right, hide[#box[Second] <dup>])
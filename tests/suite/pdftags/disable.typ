@@ -29,6 +29,24 @@
     - c
 ]
 
+--- disable-tags-redact-float pdftags pdfstandard(ua-1) ---
+= Visible
+
+#redact[
+  Some text #place(float: true, bottom + right)[explanation].
+]
+
+--- disable-tags-redact-broken-cell pdftags pdfstandard(ua-1) ---
+#set page(height: 6em)
+= Visible
+
+#redact(table(
+  rows: (4em, auto, 4em),
+  columns: 2,
+  table.cell(rowspan: 3, [a\ ] * 4),
+  [b], [c], [d],
+))
+
 --- disable-tags-partially-hidden-list pdftags pdfstandard(ua-1) ---
 // FIXME(accessibility): In realization, tags inside of list groupings aren't
 // handled. Thus if the head of the list is visible, all tags of list items
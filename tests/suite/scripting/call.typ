@@ -81,6 +81,37 @@
 // Hint: 4-15 try removing `amount:`
 #h(amount: 0.5)
 
+--- call-unknown-function-suggestion paged ---
+// Error: 2-10 unknown variable: pagebrek
+// Hint: 2-10 did you mean `pagebreak`?
+#pagebrek()
+
+--- call-unknown-variable-suggestion-local paged ---
+#let counts = (1, 2)
+// Error: 3-8 unknown variable: count
+// Hint: 3-8 did you mean `counts`?
+#(count.len())
+
+--- call-args-unexpected-suggestion-element paged ---
+// Error: 7-15 unexpected argument: fil
+// Hint: 7-15 did you mean `fill`?
+#rect(fil: red)
+
+--- call-args-unexpected-suggestion-native paged ---
+// Error: 18-26 unexpected argument: digit
+// Hint: 18-26 did you mean `digits`?
+#calc.round(1.5, digit: 1)
+
+--- call-args-unexpected-suggestion-set paged ---
+// Error: 11-21 unexpected argument: sise
+// Hint: 11-21 did you mean `size`?
+#set text(sise: 12pt)
+
+--- call-args-unexpected-suggestion-set-not-settable paged ---
+// The body can't be set, so it is not suggested in a set rule.
+// Error: 11-19 unexpected argument: bdy
+#set text(bdy: [A])
+
 --- call-args-bad-colon paged ---
 // Error: 7-8 unexpected colon
 #func(:)